@@ -0,0 +1,61 @@
+use std::fmt;
+use super::chunkid::ChunkId;
+
+#[derive(Debug)]
+pub struct Chunk<'a> {
+    id: ChunkId,
+    size: i32,
+    data: &'a [u8]
+}
+
+impl <'a> Chunk<'a> {
+    pub fn new(id: ChunkId, size: i32, data: &'a [u8]) -> Option<Chunk<'a>> {
+        if size as usize > data.len() {
+            return None
+        }
+        Some(Chunk { id: id, size: size, data: data })
+    }
+
+    pub fn len(&self) -> i32 {
+        self.size
+    }
+}
+
+impl <'a> fmt::Display for Chunk<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            r#"Chunk "{id}". Size {size} bytes"#,
+            id = self.id.to_str(),
+            size = self.size
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    const NULL: &'static [u8] = &[0; 0];
+
+    #[test]
+    fn space_for_data_is_allocated() {
+        let data = [0; 4];
+        let chunk = Chunk::new(ChunkId::from_str("data").unwrap(), 4, &data).unwrap();
+        assert!(chunk.len() == 4)
+    }
+
+    #[test]
+    fn not_enough_data() {
+        let data = [0; 4];
+        let chunk = Chunk::new(ChunkId::from_str("data").unwrap(), 8, &data);
+        assert!(chunk.is_none())
+    }
+
+    #[test]
+    fn chunk_is_displayed_correct() {
+        let chunk = Chunk::new(ChunkId::from_str("data").unwrap(), 0, NULL).unwrap();
+        assert!(format!("{}", chunk) == r#"Chunk "data". Size 0 bytes"#)
+    }
+}
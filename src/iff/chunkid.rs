@@ -0,0 +1,213 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::str;
+use std::result;
+use super::format::Format;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ChunkId([u8; 4]);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChunkIdError {
+    ShortLength,
+    UnsupportedChar,
+    SpacePrecedeLetter
+}
+
+impl fmt::Display for ChunkIdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ChunkIdError::ShortLength => {
+                write!(f, "ChunkId source should be at least 4 bytes")
+            },
+            ChunkIdError::UnsupportedChar => {
+                write!(f, "ChunkId can contain only displayable ASCII characters")
+            },
+            ChunkIdError::SpacePrecedeLetter => {
+                write!(f, "Space cannot precede letter in ChunkId")
+            }
+        }
+    }
+}
+
+pub const LOWER_CHAR_RANGE: u8 = 0x20;
+pub const UPPER_CHAR_RANGE: u8 = 0x7E;
+pub const SPACE_CHAR_CODE: u8 = 0x20;
+
+fn is_allowed_char(chr: &u8) -> bool {
+    *chr >= LOWER_CHAR_RANGE && *chr <= UPPER_CHAR_RANGE
+}
+
+fn has_precede_spaces(id: &[u8]) -> bool {
+    for idx in 0..3 {
+        if id[idx] == SPACE_CHAR_CODE && id[idx + 1] != SPACE_CHAR_CODE {
+            return true
+        }
+    }
+    false
+}
+
+pub static RESERVED_CHUNK_IDS: [&'static str; 33] = [
+    "LIST", "LIS1", "LIS2", "LIS3", "LIS4", "LIS5", "LIS6", "LIS7", "LIS8", "LIS9",
+    "FORM", "FOR1", "FOR2", "FOR3", "FOR4", "FOR5", "FOR6", "FOR7", "FOR8", "FOR9",
+    "CAT ", "CAT1", "CAT2", "CAT3", "CAT4", "CAT5", "CAT6", "CAT7", "CAT8", "CAT9",
+    "PROP",
+    "RIFF",
+    "    "
+];
+
+// RIFF only ever nests through RIFF itself or LIST; unlike IFF it has no
+// numbered FORM/CAT/PROP variants.
+static RIFF_GROUP_IDS: [&'static str; 2] = ["RIFF", "LIST"];
+
+impl ChunkId {
+    pub fn new(slice: &[u8]) -> result::Result<ChunkId, ChunkIdError> {
+        if slice.len() < 4 {
+            return Err(ChunkIdError::ShortLength)
+        }
+        if !slice[0..4].iter().all(is_allowed_char) {
+            return Err(ChunkIdError::UnsupportedChar)
+        }
+        if has_precede_spaces(slice) {
+            return Err(ChunkIdError::SpacePrecedeLetter)
+        }
+        Ok(ChunkId([slice[0], slice[1], slice[2], slice[3]]))
+    }
+
+    pub fn to_str(&self) -> &str {
+        str::from_utf8(&self.0[0..]).unwrap()
+    }
+
+    pub fn is_reserved(&self) -> bool {
+        RESERVED_CHUNK_IDS.contains(&self.to_str())
+    }
+
+    // FORM/LIST/CAT (and their numbered variants), PROP and RIFF wrap a
+    // form-type id followed by a sequence of sub-chunks, rather than raw leaf
+    // data.
+    pub fn is_group(&self) -> bool {
+        self.is_reserved() && self.to_str() != "    "
+    }
+
+    /// Like `is_group`, but restricted to the group ids that are meaningful
+    /// for `format`: IFF's `FORM`/`LIST`/`CAT`/`PROP` family excludes `RIFF`,
+    /// while RIFF only ever groups through `RIFF` or `LIST`.
+    pub fn is_group_in(&self, format: Format) -> bool {
+        match format {
+            Format::Iff => self.is_group() && self.to_str() != "RIFF",
+            Format::Riff => RIFF_GROUP_IDS.contains(&self.to_str())
+        }
+    }
+}
+
+impl fmt::Display for ChunkId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_str())
+    }
+}
+
+impl str::FromStr for ChunkId {
+    type Err = ChunkIdError;
+
+    fn from_str(s: &str) -> result::Result<ChunkId, ChunkIdError> {
+        ChunkId::new(s.as_bytes())
+    }
+}
+
+impl <'a> TryFrom<&'a [u8]> for ChunkId {
+    type Error = ChunkIdError;
+
+    fn try_from(slice: &'a [u8]) -> result::Result<ChunkId, ChunkIdError> {
+        ChunkId::new(slice)
+    }
+}
+
+impl TryFrom<[u8; 4]> for ChunkId {
+    type Error = ChunkIdError;
+
+    fn try_from(bytes: [u8; 4]) -> result::Result<ChunkId, ChunkIdError> {
+        ChunkId::new(&bytes)
+    }
+}
+
+impl <'a> TryFrom<&'a str> for ChunkId {
+    type Error = ChunkIdError;
+
+    fn try_from(s: &'a str) -> result::Result<ChunkId, ChunkIdError> {
+        ChunkId::new(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn chunk_id_is_unprintable() {
+        let id = ChunkId::new(&[0, 1, 2, 3][0..]);
+        assert!(id.unwrap_err() == ChunkIdError::UnsupportedChar)
+    }
+
+    #[test]
+    fn short_input_for_chunk_id() {
+        let id = ChunkId::new("abc".as_bytes());
+        assert!(id.unwrap_err() == ChunkIdError::ShortLength)
+    }
+
+    #[test]
+    fn chunk_id_cannot_have_inner_space() {
+        assert!(ChunkId::new(" abc".as_bytes()).unwrap_err() == ChunkIdError::SpacePrecedeLetter);
+        assert!(ChunkId::new("a bc".as_bytes()).unwrap_err() == ChunkIdError::SpacePrecedeLetter);
+        assert!(ChunkId::new("ab c".as_bytes()).unwrap_err() == ChunkIdError::SpacePrecedeLetter);
+        assert!(ChunkId::new("  ab".as_bytes()).unwrap_err() == ChunkIdError::SpacePrecedeLetter);
+        assert!(ChunkId::new("a  b".as_bytes()).unwrap_err() == ChunkIdError::SpacePrecedeLetter);
+        assert!(ChunkId::new("   a".as_bytes()).unwrap_err() == ChunkIdError::SpacePrecedeLetter)
+    }
+
+    #[test]
+    fn chunk_id_can_have_trailing_spaces() {
+        assert!(ChunkId::new("abc ".as_bytes()).is_ok())
+    }
+
+    #[test]
+    fn long_input_for_chunk() {
+        let id = ChunkId::new("abcde".as_bytes()).unwrap();
+        assert!(id.to_str() == "abcd")
+    }
+
+    #[test]
+    fn reserved_chunks() {
+        assert!(!ChunkId::from_str("FOR0").unwrap().is_reserved());
+        assert!(ChunkId::from_str("FORM").unwrap().is_reserved());
+        assert!(ChunkId::from_str("    ").unwrap().is_reserved())
+    }
+
+    #[test]
+    fn group_chunks_exclude_the_blank_placeholder_id() {
+        assert!(ChunkId::from_str("FORM").unwrap().is_group());
+        assert!(ChunkId::from_str("LIST").unwrap().is_group());
+        assert!(ChunkId::from_str("CAT ").unwrap().is_group());
+        assert!(ChunkId::from_str("PROP").unwrap().is_group());
+        assert!(!ChunkId::from_str("    ").unwrap().is_group());
+        assert!(!ChunkId::from_str("data").unwrap().is_group())
+    }
+
+    #[test]
+    fn group_detection_is_format_aware() {
+        assert!(ChunkId::from_str("FORM").unwrap().is_group_in(Format::Iff));
+        assert!(!ChunkId::from_str("RIFF").unwrap().is_group_in(Format::Iff));
+        assert!(ChunkId::from_str("RIFF").unwrap().is_group_in(Format::Riff));
+        assert!(ChunkId::from_str("LIST").unwrap().is_group_in(Format::Riff));
+        assert!(!ChunkId::from_str("FORM").unwrap().is_group_in(Format::Riff));
+        assert!(!ChunkId::from_str("PROP").unwrap().is_group_in(Format::Riff))
+    }
+
+    #[test]
+    fn try_from_byte_slice_and_array_and_str() {
+        assert!(ChunkId::try_from(&b"data"[..]).unwrap() == ChunkId::from_str("data").unwrap());
+        assert!(ChunkId::try_from(*b"data").unwrap() == ChunkId::from_str("data").unwrap());
+        assert!(ChunkId::try_from("data").unwrap() == ChunkId::from_str("data").unwrap());
+        assert!(ChunkId::try_from("ab").unwrap_err() == ChunkIdError::ShortLength)
+    }
+}
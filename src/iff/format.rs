@@ -0,0 +1,73 @@
+use std::fmt;
+use std::str;
+use std::result;
+
+/// Selects between the big-endian IFF wire format and its little-endian
+/// RIFF cousin (WAV, AVI, WebP). Both share identical 4CC id + size framing;
+/// only the size byte order and the group-container ids differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Iff,
+    Riff
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FormatError {
+    Unknown
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FormatError::Unknown => {
+                write!(f, "expected \"iff\" or \"riff\"")
+            }
+        }
+    }
+}
+
+impl Format {
+    pub fn is_big_endian(&self) -> bool {
+        match *self {
+            Format::Iff => true,
+            Format::Riff => false
+        }
+    }
+}
+
+impl str::FromStr for Format {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> result::Result<Format, FormatError> {
+        match &*s.to_lowercase() {
+            "iff" => Ok(Format::Iff),
+            "riff" => Ok(Format::Riff),
+            _ => Err(FormatError::Unknown)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn parses_known_formats_case_insensitively() {
+        assert!(Format::from_str("iff").unwrap() == Format::Iff);
+        assert!(Format::from_str("IFF").unwrap() == Format::Iff);
+        assert!(Format::from_str("riff").unwrap() == Format::Riff);
+        assert!(Format::from_str("RIFF").unwrap() == Format::Riff)
+    }
+
+    #[test]
+    fn rejects_unknown_formats() {
+        assert!(Format::from_str("wav").unwrap_err() == FormatError::Unknown)
+    }
+
+    #[test]
+    fn endianness_matches_the_format() {
+        assert!(Format::Iff.is_big_endian());
+        assert!(!Format::Riff.is_big_endian())
+    }
+}
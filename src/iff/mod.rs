@@ -0,0 +1,14 @@
+pub mod chunkid;
+pub mod format;
+pub mod chunk;
+pub mod parser;
+pub mod owned;
+pub mod reader;
+pub mod writer;
+
+pub use self::chunkid::ChunkId;
+pub use self::format::{Format, FormatError};
+pub use self::chunk::Chunk;
+pub use self::parser::{ParsedChunk, ParseError};
+pub use self::owned::OwnedChunk;
+pub use self::reader::ChunkReader;
@@ -0,0 +1,42 @@
+use super::chunkid::ChunkId;
+
+/// A chunk whose id and data are owned rather than borrowed from a buffer,
+/// produced by `ChunkReader` when streaming from an `impl Read`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct OwnedChunk {
+    id: ChunkId,
+    data: Vec<u8>
+}
+
+impl OwnedChunk {
+    pub fn new(id: ChunkId, data: Vec<u8>) -> OwnedChunk {
+        OwnedChunk { id: id, data: data }
+    }
+
+    pub fn id(&self) -> &ChunkId {
+        &self.id
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::chunkid::ChunkId;
+    use std::str::FromStr;
+
+    #[test]
+    fn exposes_id_and_data() {
+        let chunk = OwnedChunk::new(ChunkId::from_str("data").unwrap(), vec![1, 2, 3]);
+        assert!(chunk.id().to_str() == "data");
+        assert!(chunk.data() == [1, 2, 3]);
+        assert!(chunk.len() == 3)
+    }
+}
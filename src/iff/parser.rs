@@ -0,0 +1,263 @@
+use std::fmt;
+use std::result;
+use super::chunk::Chunk;
+use super::chunkid::{ChunkId, ChunkIdError};
+use super::format::Format;
+
+const HEADER_LEN: usize = 8;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParsedChunk<'a> {
+    Leaf { id: ChunkId, data: &'a [u8] },
+    Group { id: ChunkId, form_type: ChunkId, children: Vec<ParsedChunk<'a>> }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    TruncatedHeader,
+    SizeOverflowsBuffer,
+    NotAGroup,
+    InvalidChunkId(ChunkIdError)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::TruncatedHeader => {
+                write!(f, "buffer ends before a full chunk id and size could be read")
+            },
+            ParseError::SizeOverflowsBuffer => {
+                write!(f, "chunk declares more data than the buffer holds")
+            },
+            ParseError::NotAGroup => {
+                write!(f, "top-level chunk must be a group (FORM, LIST, CAT or PROP for IFF; RIFF or LIST for RIFF)")
+            },
+            ParseError::InvalidChunkId(ref err) => {
+                write!(f, "invalid chunk id: {}", err)
+            }
+        }
+    }
+}
+
+pub type Result<'a> = result::Result<ParsedChunk<'a>, ParseError>;
+
+fn read_u32_be(bytes: &[u8]) -> u32 {
+    ((bytes[0] as u32) << 24) | ((bytes[1] as u32) << 16) | ((bytes[2] as u32) << 8) | (bytes[3] as u32)
+}
+
+fn read_u32_le(bytes: &[u8]) -> u32 {
+    (bytes[0] as u32) | ((bytes[1] as u32) << 8) | ((bytes[2] as u32) << 16) | ((bytes[3] as u32) << 24)
+}
+
+fn read_size(bytes: &[u8], format: Format) -> u32 {
+    if format.is_big_endian() {
+        read_u32_be(bytes)
+    } else {
+        read_u32_le(bytes)
+    }
+}
+
+// Parses a single chunk (header + data + optional pad byte) from the front of
+// `buf`, returning the parsed chunk along with the number of bytes it occupied.
+fn parse_one(buf: &[u8], format: Format) -> result::Result<(ParsedChunk, usize), ParseError> {
+    if buf.len() < HEADER_LEN {
+        return Err(ParseError::TruncatedHeader)
+    }
+
+    let id = match ChunkId::new(&buf[0..4]) {
+        Ok(id) => id,
+        Err(err) => return Err(ParseError::InvalidChunkId(err))
+    };
+    let size = read_size(&buf[4..8], format) as usize;
+
+    let data_end = match HEADER_LEN.checked_add(size) {
+        Some(end) if end <= buf.len() => end,
+        _ => return Err(ParseError::SizeOverflowsBuffer)
+    };
+    let data = &buf[HEADER_LEN..data_end];
+    let pad = size % 2;
+    if data_end + pad > buf.len() {
+        return Err(ParseError::SizeOverflowsBuffer)
+    }
+
+    let parsed = if id.is_group_in(format) {
+        if data.len() < 4 {
+            return Err(ParseError::TruncatedHeader)
+        }
+        let form_type = match ChunkId::new(&data[0..4]) {
+            Ok(id) => id,
+            Err(err) => return Err(ParseError::InvalidChunkId(err))
+        };
+        let children = match parse_sequence(&data[4..], format) {
+            Ok(children) => children,
+            Err(err) => return Err(err)
+        };
+        ParsedChunk::Group { id: id, form_type: form_type, children: children }
+    } else {
+        ParsedChunk::Leaf { id: id, data: data }
+    };
+
+    Ok((parsed, data_end + pad))
+}
+
+fn parse_sequence(buf: &[u8], format: Format) -> result::Result<Vec<ParsedChunk>, ParseError> {
+    let mut children = Vec::new();
+    let mut rest = buf;
+    while !rest.is_empty() {
+        let (child, consumed) = match parse_one(rest, format) {
+            Ok(result) => result,
+            Err(err) => return Err(err)
+        };
+        children.push(child);
+        rest = &rest[consumed..];
+    }
+    Ok(children)
+}
+
+impl <'a> Chunk<'a> {
+    /// Parses a buffer holding a single top-level IFF chunk into a tree of
+    /// `ParsedChunk`s. The top-level chunk must be a group (`FORM`, `LIST`,
+    /// `CAT` or `PROP`, including their numbered variants); anything else is
+    /// `ParseError::NotAGroup`.
+    pub fn parse(buf: &'a [u8]) -> Result<'a> {
+        Chunk::parse_as(buf, Format::Iff)
+    }
+
+    /// Like `parse`, but for a specific `Format` (`Iff` reads big-endian sizes
+    /// and groups through `FORM`/`LIST`/`CAT`/`PROP`; `Riff` reads
+    /// little-endian sizes and groups through `RIFF`/`LIST`).
+    pub fn parse_as(buf: &'a [u8], format: Format) -> Result<'a> {
+        let (parsed, _) = match parse_one(buf, format) {
+            Ok(result) => result,
+            Err(err) => return Err(err)
+        };
+        match parsed {
+            ParsedChunk::Group { .. } => Ok(parsed),
+            ParsedChunk::Leaf { .. } => Err(ParseError::NotAGroup)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::chunk::Chunk;
+    use super::super::chunkid::ChunkId;
+    use super::super::format::Format;
+    use std::str::FromStr;
+
+    fn id(s: &str) -> ChunkId {
+        ChunkId::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn parses_a_leaf_inside_a_form() {
+        // FORM { size=16 } "TEST" + "data" { size=4 } "abcd"
+        let buf: &[u8] = &[
+            b'F', b'O', b'R', b'M', 0x00, 0x00, 0x00, 0x10,
+            b'T', b'E', b'S', b'T',
+            b'd', b'a', b't', b'a', 0x00, 0x00, 0x00, 0x04,
+            b'a', b'b', b'c', b'd'
+        ];
+        let parsed = Chunk::parse(buf).unwrap();
+        match parsed {
+            ParsedChunk::Group { id: gid, form_type, children } => {
+                assert!(gid == id("FORM"));
+                assert!(form_type == id("TEST"));
+                assert!(children.len() == 1);
+                match children[0] {
+                    ParsedChunk::Leaf { id: ref cid, data } => {
+                        assert!(*cid == id("data"));
+                        assert!(data == b"abcd")
+                    },
+                    _ => panic!("expected a leaf chunk")
+                }
+            },
+            _ => panic!("expected a group chunk")
+        }
+    }
+
+    #[test]
+    fn odd_sized_data_is_followed_by_a_pad_byte() {
+        // FORM { size=14 } "TEST" + "data" { size=1 } "a" <pad>
+        let buf: &[u8] = &[
+            b'F', b'O', b'R', b'M', 0x00, 0x00, 0x00, 0x0E,
+            b'T', b'E', b'S', b'T',
+            b'd', b'a', b't', b'a', 0x00, 0x00, 0x00, 0x01,
+            b'a', 0x00
+        ];
+        let parsed = Chunk::parse(buf).unwrap();
+        match parsed {
+            ParsedChunk::Group { children, .. } => assert!(children.len() == 1),
+            _ => panic!("expected a group chunk")
+        }
+    }
+
+    #[test]
+    fn nested_groups_parse_recursively() {
+        // LIST { size=24 } "ABCD" + PROP { size=12 } "WXYZ" + "data" { size=0 }
+        let buf: &[u8] = &[
+            b'L', b'I', b'S', b'T', 0x00, 0x00, 0x00, 0x18,
+            b'A', b'B', b'C', b'D',
+            b'P', b'R', b'O', b'P', 0x00, 0x00, 0x00, 0x0C,
+            b'W', b'X', b'Y', b'Z',
+            b'd', b'a', b't', b'a', 0x00, 0x00, 0x00, 0x00
+        ];
+        let parsed = Chunk::parse(buf).unwrap();
+        match parsed {
+            ParsedChunk::Group { id: gid, children, .. } => {
+                assert!(gid == id("LIST"));
+                assert!(children.len() == 1);
+                match children[0] {
+                    ParsedChunk::Group { id: ref pid, ref children, .. } => {
+                        assert!(*pid == id("PROP"));
+                        assert!(children.len() == 1)
+                    },
+                    _ => panic!("expected the PROP chunk to parse as a group")
+                }
+            },
+            _ => panic!("expected a group chunk")
+        }
+    }
+
+    #[test]
+    fn parses_little_endian_riff_groups() {
+        // RIFF { size=14, little-endian } "WAVE" + "data" { size=1 } "a" <pad>
+        let buf: &[u8] = &[
+            b'R', b'I', b'F', b'F', 0x0E, 0x00, 0x00, 0x00,
+            b'W', b'A', b'V', b'E',
+            b'd', b'a', b't', b'a', 0x01, 0x00, 0x00, 0x00,
+            b'a', 0x00
+        ];
+        let parsed = Chunk::parse_as(buf, Format::Riff).unwrap();
+        match parsed {
+            ParsedChunk::Group { id: gid, form_type, children } => {
+                assert!(gid == id("RIFF"));
+                assert!(form_type == id("WAVE"));
+                assert!(children.len() == 1)
+            },
+            _ => panic!("expected a group chunk")
+        }
+    }
+
+    #[test]
+    fn truncated_header_is_rejected() {
+        let buf: &[u8] = &[b'F', b'O', b'R', b'M', 0x00, 0x00];
+        assert!(Chunk::parse(buf).unwrap_err() == ParseError::TruncatedHeader)
+    }
+
+    #[test]
+    fn oversized_declared_length_is_rejected() {
+        let buf: &[u8] = &[
+            b'F', b'O', b'R', b'M', 0xFF, 0xFF, 0xFF, 0xFF,
+            b'T', b'E', b'S', b'T'
+        ];
+        assert!(Chunk::parse(buf).unwrap_err() == ParseError::SizeOverflowsBuffer)
+    }
+
+    #[test]
+    fn non_group_top_level_chunk_is_rejected() {
+        let buf: &[u8] = &[b'd', b'a', b't', b'a', 0x00, 0x00, 0x00, 0x00];
+        assert!(Chunk::parse(buf).unwrap_err() == ParseError::NotAGroup)
+    }
+}
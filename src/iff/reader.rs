@@ -0,0 +1,161 @@
+use std::io;
+use std::io::Read;
+use super::chunkid::ChunkId;
+use super::format::Format;
+use super::owned::OwnedChunk;
+
+const HEADER_LEN: usize = 8;
+
+/// Walks an `impl Read` one chunk at a time, without requiring the whole
+/// stream to be buffered in memory up front. Yields `Ok(OwnedChunk)` for each
+/// chunk in turn, stopping (`None`) at a clean end of stream, or `Err` if the
+/// stream ends in the middle of a chunk.
+pub struct ChunkReader<R> {
+    inner: R,
+    format: Format
+}
+
+impl <R: Read> ChunkReader<R> {
+    /// Reads IFF (big-endian) chunks. Use `with_format` to read RIFF instead.
+    pub fn new(inner: R) -> ChunkReader<R> {
+        ChunkReader::with_format(inner, Format::Iff)
+    }
+
+    pub fn with_format(inner: R, format: Format) -> ChunkReader<R> {
+        ChunkReader { inner: inner, format: format }
+    }
+
+    // Reads exactly `buf.len()` bytes, unless the very first read hits end of
+    // stream, in which case `Ok(false)` signals a clean end.
+    fn fill_or_eof(&mut self, buf: &mut [u8]) -> io::Result<bool> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            let n = match self.inner.read(&mut buf[filled..]) {
+                Ok(n) => n,
+                Err(err) => return Err(err)
+            };
+            if n == 0 {
+                if filled == 0 {
+                    return Ok(false)
+                }
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated chunk"))
+            }
+            filled += n;
+        }
+        Ok(true)
+    }
+}
+
+fn read_u32_be(bytes: &[u8]) -> u32 {
+    ((bytes[0] as u32) << 24) | ((bytes[1] as u32) << 16) | ((bytes[2] as u32) << 8) | (bytes[3] as u32)
+}
+
+fn read_u32_le(bytes: &[u8]) -> u32 {
+    (bytes[0] as u32) | ((bytes[1] as u32) << 8) | ((bytes[2] as u32) << 16) | ((bytes[3] as u32) << 24)
+}
+
+impl <R: Read> Iterator for ChunkReader<R> {
+    type Item = io::Result<OwnedChunk>;
+
+    fn next(&mut self) -> Option<io::Result<OwnedChunk>> {
+        let mut header = [0u8; HEADER_LEN];
+        match self.fill_or_eof(&mut header) {
+            Ok(true) => {},
+            Ok(false) => return None,
+            Err(err) => return Some(Err(err))
+        }
+
+        let id = match ChunkId::new(&header[0..4]) {
+            Ok(id) => id,
+            Err(err) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, err.to_string())))
+        };
+        let size = if self.format.is_big_endian() {
+            read_u32_be(&header[4..8])
+        } else {
+            read_u32_le(&header[4..8])
+        } as usize;
+
+        let mut data = vec![0u8; size];
+        match self.fill_or_eof(&mut data) {
+            Ok(true) => {},
+            Ok(false) => return Some(Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated chunk"))),
+            Err(err) => return Some(Err(err))
+        }
+
+        if size % 2 == 1 {
+            let mut pad = [0u8; 1];
+            match self.fill_or_eof(&mut pad) {
+                Ok(true) => {},
+                Ok(false) => return Some(Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated chunk pad byte"))),
+                Err(err) => return Some(Err(err))
+            }
+        }
+
+        Some(Ok(OwnedChunk::new(id, data)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+    use super::*;
+    use super::super::chunkid::ChunkId;
+    use std::str::FromStr;
+
+    #[test]
+    fn reads_a_single_chunk() {
+        let buf: &[u8] = &[
+            b'd', b'a', b't', b'a', 0x00, 0x00, 0x00, 0x04,
+            b'a', b'b', b'c', b'd'
+        ];
+        let mut reader = ChunkReader::new(Cursor::new(buf));
+        let chunk = reader.next().unwrap().unwrap();
+        assert!(*chunk.id() == ChunkId::from_str("data").unwrap());
+        assert!(chunk.data() == b"abcd");
+        assert!(reader.next().is_none())
+    }
+
+    #[test]
+    fn reads_several_chunks_back_to_back() {
+        let buf: &[u8] = &[
+            b'd', b'a', b't', b'a', 0x00, 0x00, 0x00, 0x01,
+            b'a', 0x00,
+            b'b', b'o', b'd', b'y', 0x00, 0x00, 0x00, 0x02,
+            b'h', b'i'
+        ];
+        let mut reader = ChunkReader::new(Cursor::new(buf));
+        let first = reader.next().unwrap().unwrap();
+        assert!(first.data() == b"a");
+        let second = reader.next().unwrap().unwrap();
+        assert!(second.data() == b"hi");
+        assert!(reader.next().is_none())
+    }
+
+    #[test]
+    fn reads_little_endian_sizes_in_riff_mode() {
+        let buf: &[u8] = &[b'd', b'a', b't', b'a', 0x01, 0x00, 0x00, 0x00, b'a', 0x00];
+        let mut reader = ChunkReader::with_format(Cursor::new(buf), Format::Riff);
+        let chunk = reader.next().unwrap().unwrap();
+        assert!(chunk.data() == b"a")
+    }
+
+    #[test]
+    fn empty_stream_yields_no_chunks() {
+        let mut reader = ChunkReader::new(Cursor::new(&[][..]));
+        assert!(reader.next().is_none())
+    }
+
+    #[test]
+    fn truncated_data_is_an_error() {
+        let buf: &[u8] = &[b'd', b'a', b't', b'a', 0x00, 0x00, 0x00, 0x04, b'a', b'b'];
+        let mut reader = ChunkReader::new(Cursor::new(buf));
+        assert!(reader.next().unwrap().is_err())
+    }
+
+    #[test]
+    fn truncated_header_is_an_error() {
+        let buf: &[u8] = &[b'd', b'a', b't', b'a', 0x00, 0x00];
+        let mut reader = ChunkReader::new(Cursor::new(buf));
+        assert!(reader.next().unwrap().is_err())
+    }
+}